@@ -92,6 +92,22 @@ impl CanRepresentPi for f64 {
     fn pi() -> Self { std::f64::consts::PI }
 }
 
+/// selects whether a window is generated in its *symmetric* form, where
+/// `w[0]` and `w[size - 1]` reach the same value (the classic form, suited
+/// to FIR filter design), or its *periodic* (DFT-even) form, where the
+/// implicit `size + 1`th sample of the symmetric window is dropped so that
+/// the window never repeats its endpoint. periodic windows are what you
+/// want for STFT-style framing, since overlap-add of symmetric windows
+/// introduces a discontinuity at the frame boundary.
+///
+/// [`triangle_at`](fn.triangle_at.html) reads this flag differently: there
+/// it only selects the half-width, and both forms repeat their endpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symmetry {
+    Symmetric,
+    Periodic,
+}
+
 /// holds the window coefficients and
 /// iteration state of a cosine window iterator
 pub struct CosineWindowIter<T> {
@@ -101,6 +117,7 @@ pub struct CosineWindowIter<T> {
     pub d: T,
     pub index: usize,
     pub size: usize,
+    pub symmetry: Symmetry,
 }
 
 impl<T: Float + CanRepresentPi> Iterator for CosineWindowIter<T> {
@@ -117,7 +134,8 @@ impl<T: Float + CanRepresentPi> Iterator for CosineWindowIter<T> {
                        self.c,
                        self.d,
                        self.size,
-                       index))
+                       index,
+                       self.symmetry))
     }
 }
 
@@ -125,7 +143,9 @@ impl<T: Float + CanRepresentPi> Iterator for CosineWindowIter<T> {
 /// window](https://en.wikipedia.org/wiki/Window_function#Higher-order_generalized_cosine_windows)
 /// of `size`
 /// with the coefficients `a`, `b`, `c` and `d`
-/// at index `index`
+/// at index `index`.
+/// `symmetry` selects between the symmetric and periodic (DFT-even) forms;
+/// see [`Symmetry`](enum.Symmetry.html).
 #[inline]
 pub fn cosine_at<T: Float + CanRepresentPi>(
     a: T,
@@ -133,10 +153,15 @@ pub fn cosine_at<T: Float + CanRepresentPi>(
     c: T,
     d: T,
     size: usize,
-    index: usize)
+    index: usize,
+    symmetry: Symmetry)
     -> T {
         let pi: T = T::pi();
-        let x: T = (pi * from!(T, index)) / from!(T, size - 1);
+        let denom = match symmetry {
+            Symmetry::Symmetric => size - 1,
+            Symmetry::Periodic => size,
+        };
+        let x: T = (pi * from!(T, index)) / from!(T, denom);
         let b_ = b * (from!(T, 2.) * x).cos();
         let c_ = c * (from!(T, 4.) * x).cos();
         let d_ = d * (from!(T, 6.) * x).cos();
@@ -145,13 +170,28 @@ pub fn cosine_at<T: Float + CanRepresentPi>(
 
 /// returns an iterator that yields the values for a [cosine
 /// window](https://en.wikipedia.org/wiki/Window_function#Hann_.28Hanning.29_window) of `size`
-/// with the coefficients `a`, `b`, `c` and `d`
+/// with the coefficients `a`, `b`, `c` and `d`, in its symmetric form.
 pub fn cosine_iter<T: Float + CanRepresentPi>(
     a: T,
     b: T,
     c: T,
     d: T,
     size: usize)
+    -> CosineWindowIter<T> {
+        cosine_iter_with_symmetry::<T>(a, b, c, d, size, Symmetry::Symmetric)
+    }
+
+/// returns an iterator that yields the values for a [cosine
+/// window](https://en.wikipedia.org/wiki/Window_function#Hann_.28Hanning.29_window) of `size`
+/// with the coefficients `a`, `b`, `c` and `d`, in either the symmetric or
+/// periodic (DFT-even) form, as selected by `symmetry`.
+pub fn cosine_iter_with_symmetry<T: Float + CanRepresentPi>(
+    a: T,
+    b: T,
+    c: T,
+    d: T,
+    size: usize,
+    symmetry: Symmetry)
     -> CosineWindowIter<T> {
         assert!(size > 1);
         CosineWindowIter {
@@ -161,6 +201,7 @@ pub fn cosine_iter<T: Float + CanRepresentPi>(
             d: d,
             index: 0,
             size: size,
+            symmetry: symmetry,
         }
     }
 
@@ -175,6 +216,19 @@ pub fn hanning_iter<T: Float + CanRepresentPi>(size: usize) -> CosineWindowIter<
         size)
 }
 
+/// returns an iterator that yields the values for a periodic (DFT-even)
+/// [hanning
+/// window](https://en.wikipedia.org/wiki/Window_function#Hann_.28Hanning.29_window) of `size`
+pub fn hanning_iter_periodic<T: Float + CanRepresentPi>(size: usize) -> CosineWindowIter<T> {
+    cosine_iter_with_symmetry::<T>(
+        from!(T, 0.5),
+        from!(T, 0.5),
+        from!(T, 0.),
+        from!(T, 0.),
+        size,
+        Symmetry::Periodic)
+}
+
 /// returns an iterator that yields the values for a [hamming
 /// window](https://en.wikipedia.org/wiki/Window_function#Hamming_window) of `size`
 pub fn hamming_iter<T: Float + CanRepresentPi>(size: usize) -> CosineWindowIter<T> {
@@ -186,6 +240,19 @@ pub fn hamming_iter<T: Float + CanRepresentPi>(size: usize) -> CosineWindowIter<
         size)
 }
 
+/// returns an iterator that yields the values for a periodic (DFT-even)
+/// [hamming
+/// window](https://en.wikipedia.org/wiki/Window_function#Hamming_window) of `size`
+pub fn hamming_iter_periodic<T: Float + CanRepresentPi>(size: usize) -> CosineWindowIter<T> {
+    cosine_iter_with_symmetry::<T>(
+        from!(T, 0.54),
+        from!(T, 0.46),
+        from!(T, 0.),
+        from!(T, 0.),
+        size,
+        Symmetry::Periodic)
+}
+
 /// returns an iterator that yields the values for a [blackman
 /// window](https://en.wikipedia.org/wiki/Window_function#Blackman_windows) of `size`
 pub fn blackman_iter<T: Float + CanRepresentPi>(size: usize) -> CosineWindowIter<T> {
@@ -197,6 +264,19 @@ pub fn blackman_iter<T: Float + CanRepresentPi>(size: usize) -> CosineWindowIter
         size)
 }
 
+/// returns an iterator that yields the values for a periodic (DFT-even)
+/// [blackman
+/// window](https://en.wikipedia.org/wiki/Window_function#Blackman_windows) of `size`
+pub fn blackman_iter_periodic<T: Float + CanRepresentPi>(size: usize) -> CosineWindowIter<T> {
+    cosine_iter_with_symmetry::<T>(
+        from!(T, 0.35875),
+        from!(T, 0.48829),
+        from!(T, 0.14128),
+        from!(T, 0.01168),
+        size,
+        Symmetry::Periodic)
+}
+
 /// returns an iterator that yields the values for a [nuttall
 /// window](https://en.wikipedia.org/wiki/Window_function#Nuttall_window.2C_continuous_first_derivative) of `size`
 pub fn nuttall_iter<T: Float + CanRepresentPi>(size: usize) -> CosineWindowIter<T> {
@@ -207,3 +287,464 @@ pub fn nuttall_iter<T: Float + CanRepresentPi>(size: usize) -> CosineWindowIter<
         from!(T, 0.012604),
         size)
 }
+
+/// returns an iterator that yields the values for a periodic (DFT-even)
+/// [nuttall
+/// window](https://en.wikipedia.org/wiki/Window_function#Nuttall_window.2C_continuous_first_derivative) of `size`
+pub fn nuttall_iter_periodic<T: Float + CanRepresentPi>(size: usize) -> CosineWindowIter<T> {
+    cosine_iter_with_symmetry::<T>(
+        from!(T, 0.355768),
+        from!(T, 0.487396),
+        from!(T, 0.144232),
+        from!(T, 0.012604),
+        size,
+        Symmetry::Periodic)
+}
+
+/// unifies the generalized-cosine windows behind one abstraction, so generic
+/// DSP code (an STFT implementation, say) can be parameterized over "which
+/// window" instead of matching on one of the `*_iter` functions or a function
+/// pointer. its implementors are `Hanning`, `Hamming`, `Blackman`, `Nuttall`
+/// and `Cosine`; the triangular, gaussian and rectangular windows do not
+/// implement it.
+pub trait WindowFunction<T: Float + CanRepresentPi>: Copy {
+    /// the value of this window of `size` at `index`.
+    fn value_at(&self, index: usize, size: usize) -> T;
+
+    /// whether this window is generated in its symmetric form (`true`) or
+    /// its periodic / DFT-even form (`false`); see [`Symmetry`](enum.Symmetry.html).
+    fn is_symmetric(&self) -> bool;
+
+    /// returns an iterator that yields this window's values for `size`.
+    fn iter(&self, size: usize) -> WindowFunctionIter<T, Self> {
+        assert!(size > 1);
+        WindowFunctionIter {
+            window: *self,
+            size: size,
+            index: 0,
+            _marker: ::std::marker::PhantomData,
+        }
+    }
+}
+
+/// iterator that yields the values of a [`WindowFunction`](trait.WindowFunction.html)
+/// for a given size; returned by `WindowFunction::iter`.
+pub struct WindowFunctionIter<T, W> {
+    window: W,
+    size: usize,
+    index: usize,
+    _marker: ::std::marker::PhantomData<T>,
+}
+
+impl<T: Float + CanRepresentPi, W: WindowFunction<T>> Iterator for WindowFunctionIter<T, W> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.index == self.size {
+            return None;
+        }
+        let index = self.index;
+        self.index += 1;
+        Some(self.window.value_at(index, self.size))
+    }
+}
+
+/// [`WindowFunction`](trait.WindowFunction.html) implementor for the hanning window, in
+/// either its symmetric or periodic (DFT-even) form; see [`Symmetry`](enum.Symmetry.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hanning {
+    pub symmetry: Symmetry,
+}
+
+impl<T: Float + CanRepresentPi> WindowFunction<T> for Hanning {
+    fn value_at(&self, index: usize, size: usize) -> T {
+        cosine_at(from!(T, 0.5), from!(T, 0.5), from!(T, 0.), from!(T, 0.), size, index, self.symmetry)
+    }
+
+    fn is_symmetric(&self) -> bool {
+        self.symmetry == Symmetry::Symmetric
+    }
+}
+
+/// [`WindowFunction`](trait.WindowFunction.html) implementor for the hamming window, in
+/// either its symmetric or periodic (DFT-even) form; see [`Symmetry`](enum.Symmetry.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hamming {
+    pub symmetry: Symmetry,
+}
+
+impl<T: Float + CanRepresentPi> WindowFunction<T> for Hamming {
+    fn value_at(&self, index: usize, size: usize) -> T {
+        cosine_at(from!(T, 0.54), from!(T, 0.46), from!(T, 0.), from!(T, 0.), size, index, self.symmetry)
+    }
+
+    fn is_symmetric(&self) -> bool {
+        self.symmetry == Symmetry::Symmetric
+    }
+}
+
+/// [`WindowFunction`](trait.WindowFunction.html) implementor for the blackman window, in
+/// either its symmetric or periodic (DFT-even) form; see [`Symmetry`](enum.Symmetry.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Blackman {
+    pub symmetry: Symmetry,
+}
+
+impl<T: Float + CanRepresentPi> WindowFunction<T> for Blackman {
+    fn value_at(&self, index: usize, size: usize) -> T {
+        cosine_at(from!(T, 0.35875), from!(T, 0.48829), from!(T, 0.14128), from!(T, 0.01168), size, index, self.symmetry)
+    }
+
+    fn is_symmetric(&self) -> bool {
+        self.symmetry == Symmetry::Symmetric
+    }
+}
+
+/// [`WindowFunction`](trait.WindowFunction.html) implementor for the nuttall window, in
+/// either its symmetric or periodic (DFT-even) form; see [`Symmetry`](enum.Symmetry.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Nuttall {
+    pub symmetry: Symmetry,
+}
+
+impl<T: Float + CanRepresentPi> WindowFunction<T> for Nuttall {
+    fn value_at(&self, index: usize, size: usize) -> T {
+        cosine_at(from!(T, 0.355768), from!(T, 0.487396), from!(T, 0.144232), from!(T, 0.012604), size, index, self.symmetry)
+    }
+
+    fn is_symmetric(&self) -> bool {
+        self.symmetry == Symmetry::Symmetric
+    }
+}
+
+/// generic generalized-cosine [`WindowFunction`](trait.WindowFunction.html) implementor,
+/// for callers who want to supply their own `a`, `b`, `c` and `d` coefficients
+/// (see [`cosine_at`](fn.cosine_at.html)) instead of using one of the named windows.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cosine<T> {
+    pub a: T,
+    pub b: T,
+    pub c: T,
+    pub d: T,
+    pub symmetry: Symmetry,
+}
+
+impl<T: Float + CanRepresentPi> WindowFunction<T> for Cosine<T> {
+    fn value_at(&self, index: usize, size: usize) -> T {
+        cosine_at(self.a, self.b, self.c, self.d, size, index, self.symmetry)
+    }
+
+    fn is_symmetric(&self) -> bool {
+        self.symmetry == Symmetry::Symmetric
+    }
+}
+
+/// holds the iteration state of a triangular window iterator
+pub struct TriangleWindowIter<T> {
+    pub index: usize,
+    pub size: usize,
+    pub symmetry: Symmetry,
+    marker: ::std::marker::PhantomData<T>,
+}
+
+impl<T: Float> Iterator for TriangleWindowIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.index == self.size {
+            return None;
+        }
+        let index = self.index;
+        self.index += 1;
+        Some(triangle_at(self.size, index, self.symmetry))
+    }
+}
+
+/// returns the value of the [triangular
+/// window](https://en.wikipedia.org/wiki/Window_function#Triangular_window)
+/// of `size` at `index`.
+///
+/// `symmetry` selects the half-width `L` the window is normalized by:
+/// `Symmetric` gives the classic Bartlett window, `L = (size - 1) / 2`,
+/// whose endpoints reach zero; `Periodic` gives `L = size / 2`, whose
+/// endpoints are nonzero. the peak is a single sample at the center for
+/// odd `size`, and a two-sample plateau for even `size`.
+#[inline]
+pub fn triangle_at<T: Float>(size: usize, index: usize, symmetry: Symmetry) -> T {
+    let center = from!(T, size - 1) / from!(T, 2.);
+    let l = match symmetry {
+        Symmetry::Symmetric => from!(T, size - 1) / from!(T, 2.),
+        Symmetry::Periodic => from!(T, size) / from!(T, 2.),
+    };
+    let distance = from!(T, index) - center;
+    T::one() - (distance / l).abs()
+}
+
+/// returns an iterator that yields the values for a [triangular
+/// window](https://en.wikipedia.org/wiki/Window_function#Triangular_window) of `size`
+pub fn triangle_iter<T: Float>(size: usize, symmetry: Symmetry) -> TriangleWindowIter<T> {
+    assert!(size > 1);
+    TriangleWindowIter {
+        index: 0,
+        size: size,
+        symmetry: symmetry,
+        marker: ::std::marker::PhantomData,
+    }
+}
+
+/// holds the iteration state of a gaussian window iterator
+pub struct GaussianWindowIter<T> {
+    pub sigma: T,
+    pub index: usize,
+    pub size: usize,
+}
+
+impl<T: Float> Iterator for GaussianWindowIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.index == self.size {
+            return None;
+        }
+        let index = self.index;
+        self.index += 1;
+        Some(gaussian_at(self.size, index, self.sigma))
+    }
+}
+
+/// returns the value of the [gaussian
+/// window](https://en.wikipedia.org/wiki/Window_function#Gaussian_window)
+/// of `size` at `index`, with width controlled by `sigma` (typically
+/// `0 < sigma <= 0.5`; smaller values make the window narrower).
+///
+/// the gaussian window's endpoints are nonzero (`exp(-0.5 / sigma^2)`):
+/// it never reaches zero within a finite `size`.
+///
+/// ```
+/// use apodize::gaussian_at;
+///
+/// // reference values for a size-5 gaussian window with sigma = 0.4
+/// let size = 5;
+/// let sigma = 0.4;
+/// let window: Vec<f64> = (0..size).map(|i| gaussian_at(size, i, sigma)).collect();
+///
+/// assert!((window[0] - 0.04393693362340742).abs() < 1e-12);
+/// assert!((window[1] - 0.45783336177161427).abs() < 1e-12);
+/// assert!((window[2] - 1.0).abs() < 1e-12);
+/// assert_eq!(window[0], window[4]);
+/// assert_eq!(window[1], window[3]);
+/// ```
+#[inline]
+pub fn gaussian_at<T: Float>(size: usize, index: usize, sigma: T) -> T {
+    let center = from!(T, size - 1) / from!(T, 2.);
+    let denom = sigma * center;
+    let x = (from!(T, index) - center) / denom;
+    (from!(T, -0.5) * x * x).exp()
+}
+
+/// returns an iterator that yields the values for a [gaussian
+/// window](https://en.wikipedia.org/wiki/Window_function#Gaussian_window) of `size`,
+/// with width controlled by `sigma`
+///
+/// ```
+/// use apodize::gaussian_iter;
+///
+/// // reference values for a size-6 gaussian window with sigma = 0.5
+/// let window: Vec<f64> = gaussian_iter(6, 0.5).collect();
+///
+/// assert!((window[0] - 0.1353352832366127).abs() < 1e-12);
+/// assert!((window[1] - 0.4867522559599717).abs() < 1e-12);
+/// assert!((window[2] - 0.9231163463866358).abs() < 1e-12);
+/// assert_eq!(window[0], window[5]);
+/// assert_eq!(window[1], window[4]);
+/// assert_eq!(window[2], window[3]);
+/// ```
+pub fn gaussian_iter<T: Float>(size: usize, sigma: T) -> GaussianWindowIter<T> {
+    assert!(size > 1);
+    GaussianWindowIter {
+        sigma: sigma,
+        index: 0,
+        size: size,
+    }
+}
+
+/// selects how a window's coefficients are rescaled by
+/// [`NormalizedWindowIter`](struct.NormalizedWindowIter.html); useful when
+/// windows are used for overlap-add resynthesis or coherent-gain
+/// correction, where callers need the coefficients scaled rather than raw.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Normalization<T> {
+    /// divide every coefficient by the window's peak (maximum) coefficient.
+    PeakUnity,
+    /// divide every coefficient by the sum of all of the window's
+    /// coefficients, so the window integrates to one.
+    SumUnity,
+    /// divide every coefficient by a caller-supplied constant.
+    Factor(T),
+}
+
+/// wraps any window iterator, rescaling every value it yields by a
+/// precomputed normalization factor.
+pub struct NormalizedWindowIter<T, I> {
+    inner: I,
+    factor: T,
+}
+
+impl<T: Float, I: Iterator<Item = T>> NormalizedWindowIter<T, I> {
+    /// wraps `inner`, rescaling every value it yields by `factor`.
+    pub fn with_factor(inner: I, factor: T) -> Self {
+        NormalizedWindowIter {
+            inner: inner,
+            factor: factor,
+        }
+    }
+}
+
+impl<T: Float, I: Iterator<Item = T>> Iterator for NormalizedWindowIter<T, I> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.inner.next().map(|v| v / self.factor)
+    }
+}
+
+/// wraps a generalized-cosine window iterator, normalizing it according to
+/// `mode`. the coefficient sum is only cheap for the periodic form with
+/// `size > 3`, where it's exactly `a * size` (for `size` 2 or 3 the `c` or
+/// `d` term does not cancel, so the sum is folded); neither the symmetric sum nor the discrete peak
+/// has a closed form that holds for every `(symmetry, size)` combination
+/// (the continuous peak `a + b + c + d` is only reached by a sample when
+/// `size` is odd and symmetric, or even and periodic), so both fall back to
+/// folding `cosine_at` over the window.
+pub fn cosine_iter_normalized<T: Float + CanRepresentPi>(
+    a: T,
+    b: T,
+    c: T,
+    d: T,
+    size: usize,
+    symmetry: Symmetry,
+    mode: Normalization<T>)
+    -> NormalizedWindowIter<T, CosineWindowIter<T>> {
+        let factor = match mode {
+            Normalization::PeakUnity => {
+                (0..size).fold(T::neg_infinity(), |acc, index| {
+                    acc.max(cosine_at(a, b, c, d, size, index, symmetry))
+                })
+            }
+            Normalization::SumUnity => match symmetry {
+                Symmetry::Periodic if size > 3 => a * from!(T, size),
+                _ => {
+                    (0..size).fold(T::zero(), |acc, index| {
+                        acc + cosine_at(a, b, c, d, size, index, symmetry)
+                    })
+                }
+            },
+            Normalization::Factor(f) => f,
+        };
+        NormalizedWindowIter::with_factor(
+            cosine_iter_with_symmetry(a, b, c, d, size, symmetry),
+            factor)
+    }
+
+/// normalizes an arbitrary finite window iterator according to
+/// `mode`. unlike [`cosine_iter_normalized`](fn.cosine_iter_normalized.html),
+/// there is no closed-form shortcut here: `PeakUnity` and `SumUnity` need a
+/// full pass over `iter`'s values before any normalized value can be
+/// yielded, so `iter` is collected up front.
+pub fn normalized_iter<T: Float, I: Iterator<Item = T>>(
+    iter: I,
+    mode: Normalization<T>)
+    -> NormalizedWindowIter<T, ::std::vec::IntoIter<T>> {
+        let values: Vec<T> = iter.collect();
+        let factor = match mode {
+            Normalization::PeakUnity => values.iter().cloned().fold(T::neg_infinity(), T::max),
+            Normalization::SumUnity => values.iter().fold(T::zero(), |acc, &v| acc + v),
+            Normalization::Factor(f) => f,
+        };
+        NormalizedWindowIter::with_factor(values.into_iter(), factor)
+    }
+
+/// holds the iteration state of a rectangular (boxcar) window iterator
+pub struct RectangularWindowIter<T> {
+    pub index: usize,
+    pub size: usize,
+    marker: ::std::marker::PhantomData<T>,
+}
+
+impl<T: Float> Iterator for RectangularWindowIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.index == self.size {
+            return None;
+        }
+        self.index += 1;
+        Some(T::one())
+    }
+}
+
+/// returns an iterator that yields `T::one()` for every one of the `size`
+/// samples of a [rectangular
+/// window](https://en.wikipedia.org/wiki/Window_function#Rectangular_window)
+pub fn rectangular_iter<T: Float>(size: usize) -> RectangularWindowIter<T> {
+    assert!(size > 0);
+    RectangularWindowIter {
+        index: 0,
+        size: size,
+        marker: ::std::marker::PhantomData,
+    }
+}
+
+/// names the windows that [`window_iter`](fn.window_iter.html) can build at
+/// runtime, e.g. from a config string or UI, instead of calling one of the
+/// `*_iter` functions directly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WindowType<T> {
+    Rectangular,
+    Triangular,
+    Hanning,
+    Hamming,
+    Blackman,
+    Nuttall,
+    Gaussian { sigma: T },
+}
+
+/// builds a boxed iterator yielding the window named by `kind`, of `size`,
+/// with the given `symmetry`. this is the dynamic-dispatch counterpart to
+/// the [`WindowFunction`](trait.WindowFunction.html) trait: reach for
+/// `window_iter` when the window type is only known at runtime, and for
+/// `WindowFunction` when it's known at compile time and is one of the
+/// generalized-cosine windows.
+///
+/// `symmetry` has no periodic form to select for `Rectangular` (constant
+/// for every `size`) or `Gaussian` (already has no discontinuity at its
+/// endpoints), so it's silently ignored for those two variants.
+pub fn window_iter<T: Float + CanRepresentPi + 'static>(
+    kind: WindowType<T>,
+    size: usize,
+    symmetry: Symmetry)
+    -> Box<dyn Iterator<Item = T>> {
+        match kind {
+            // symmetry has no effect here; see the note on this function's doc comment.
+            WindowType::Rectangular => Box::new(rectangular_iter::<T>(size)),
+            WindowType::Triangular => Box::new(triangle_iter::<T>(size, symmetry)),
+            WindowType::Hanning => match symmetry {
+                Symmetry::Symmetric => Box::new(hanning_iter::<T>(size)),
+                Symmetry::Periodic => Box::new(hanning_iter_periodic::<T>(size)),
+            },
+            WindowType::Hamming => match symmetry {
+                Symmetry::Symmetric => Box::new(hamming_iter::<T>(size)),
+                Symmetry::Periodic => Box::new(hamming_iter_periodic::<T>(size)),
+            },
+            WindowType::Blackman => match symmetry {
+                Symmetry::Symmetric => Box::new(blackman_iter::<T>(size)),
+                Symmetry::Periodic => Box::new(blackman_iter_periodic::<T>(size)),
+            },
+            WindowType::Nuttall => match symmetry {
+                Symmetry::Symmetric => Box::new(nuttall_iter::<T>(size)),
+                Symmetry::Periodic => Box::new(nuttall_iter_periodic::<T>(size)),
+            },
+            // symmetry has no effect here; see the note on this function's doc comment.
+            WindowType::Gaussian { sigma } => Box::new(gaussian_iter::<T>(size, sigma)),
+        }
+    }